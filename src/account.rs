@@ -1,79 +1,147 @@
 //! The account module captures tenancy profiles and writes the default values to the config file. User credentials are written with a separate function to allow for additional admin users to be created.
 //! # Example
-//! ```rust
+//! ```rust,no_run
 //! use oci_config_writer::account::{default, admin};
 //! use oci_config_writer::region::identifier;
-//! 
+//!
 //! default(
 //!     ".oci/config",
+//!     "DEV",
 //!     "ocid1.user.oc1..aaaaaaaaxxxxxx",
 //!     "ocid1.fingerprint.oc1..aaaaaaaaxxxxxx",
 //!     "path/to/private/key",
 //!     "ocid1.tenancy.oc1..aaaaaaaaxxxxxx",
 //!     "IAD"
-//! );
+//! ).unwrap();
 //! admin(
+//!     ".oci/config",
+//!     "ADMIN_USER",
 //!     "ocid1.user.oc1..aaaaaaaaxxxxxx",
 //!     "ocid1.fingerprint.oc1..aaaaaaaaxxxxxx",
 //!     "path/to/private/key",
 //!     "passphrase"
-//! );
+//! ).unwrap();
 //! ```
-use directories::UserDirs;
+use ini::Ini;
+use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::fs::OpenOptions;
 use std::io::prelude::*;
-use std::io;
-use crate::region::identifier;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::config::list_profiles;
+use crate::error::ConfigError;
 
-/// represents the DEFAULT section of the config file.
+/// represents a named section of the config file (`DEFAULT`, or a caller-chosen profile such as `DEV`/`PROD`).
 // Define the struct representing a file entry
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Profile {
-    user: &'static str,
-    fingerprint: &'static str,
-    key_file: &'static str,
-    tenancy: &'static str,
-    region: &'static str, // selection of active regions
+    profile_name: String,
+    user: String,
+    fingerprint: String,
+    key_file: String,
+    tenancy: String,
+    region: String, // selection of active regions
 }
 
 impl Profile {
+    /// builds a [`Profile`] from its config values, e.g. when re-hydrating one parsed by [`crate::config`].
+    pub fn new(profile_name: String, user: String, fingerprint: String, key_file: String, tenancy: String, region: String) -> Profile {
+        Self {
+            profile_name,
+            user,
+            fingerprint,
+            key_file,
+            tenancy,
+            region,
+        }
+    }
+
+    /// the tenancy OCID that anchors a [`sign::sign`](crate::sign::sign) `keyId`.
+    pub(crate) fn tenancy(&self) -> &str {
+        &self.tenancy
+    }
+
     // Function to format the Profile struct as a string
     fn profile_entry(&self) -> String {
-        format!("[DEFAULT]\nuser: {}\nfingerprint: {}\nkey_file: {}\ntenancy: {}\nregion: {}\n\n", 
-        self.user, self.fingerprint, self.key_file, self.tenancy, self.region)
+        format!("[{}]\nuser: {}\nfingerprint: {}\nkey_file: {}\ntenancy: {}\nregion: {}\n\n",
+        self.profile_name, self.user, self.fingerprint, self.key_file, self.tenancy, self.region)
     }
-    
+
     // Function to write the struct to the config file
-    fn write_to_config(&self, path: &str) -> io::Result<()> {
-        // define directory directory
-        let config_path = UserDirs::new().unwrap().home_dir().join(path);
-        let path_to_str = config_path.to_str().expect("Failed to convert path to str");
+    fn write_to_config(&self, path: &str) -> Result<(), ConfigError> {
+        // `path` is already fully resolved by `crate::location::resolve`; write it as-is.
+        let config_path = PathBuf::from(path);
+
+        // read first: refuse to duplicate a section that is already there
+        if list_profiles(config_path.to_str().unwrap_or_default())
+            .iter()
+            .any(|name| name == &self.profile_name)
+        {
+            return Err(ConfigError::ConfigExists);
+        }
 
         // set modification properties
-        let config = OpenOptions::new()
+        let mut config = OpenOptions::new()
             .write(true)
             .append(true)
-            .open(path_to_str);
-        match config {
-            Ok(mut config) => {
-                match config.write_all(
-                    self.profile_entry().as_bytes(),
-                ) {
-                    Ok(_) => println!("Tenancy data written to file successfully"),
-                    Err(e) => println!("Failed to write tenancy data to file: {}", e),
-                }
-            }
-            Err(e) => println!("Failed to create file: {}", e),
-        }
-    
-        Ok(())
+            .open(&config_path)
+            .map_err(|_| ConfigError::PathNotAccessible { path: config_path.clone() })?;
+
+        config
+            .write_all(self.profile_entry().as_bytes())
+            .map_err(|source| ConfigError::WriteContentFailed { file: config_path, source })
     }
 }
 
+/// parses a [`Profile`] back out of its `profile_entry` INI section text, the same format
+/// [`crate::config::get_profile`] reads from disk, so a config section can be edited in memory
+/// and re-serialized with [`fmt::Display`] instead of only appended.
+impl FromStr for Profile {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| ConfigError::InvalidProfile { reason: reason.to_string() };
+
+        let ini = Ini::load_from_str(s).map_err(|e| invalid(&e.to_string()))?;
+        let name = ini.sections().flatten().next().ok_or_else(|| invalid("missing section header"))?.to_string();
+        let section = ini.section(Some(name.as_str())).ok_or_else(|| invalid("missing section header"))?;
+
+        let get = |key: &str| -> Result<String, ConfigError> {
+            section.get(key).map(str::to_string).ok_or_else(|| invalid(&format!("missing key: {key}")))
+        };
+
+        Ok(Profile::new(name, get("user")?, get("fingerprint")?, get("key_file")?, get("tenancy")?, get("region")?))
+    }
+}
+
+/// renders a [`Profile`] as the same INI section text [`crate::config::get_profile`] parses, so
+/// `s.parse::<Profile>()?.to_string()` round-trips through the config file's on-disk format.
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.profile_entry())
+    }
+}
+
+/// writes a named profile section to the config file. Fails with [`ConfigError::ConfigExists`] if `profile_name` is already present.
+pub fn default(path: &str, profile_name: &str, user: &str, fingerprint: &str, key_file: &str, tenancy: &str, region: &str) -> Result<(), ConfigError> {
+    let default_profile = Profile::new(
+        profile_name.to_string(),
+        user.to_string(),
+        fingerprint.to_string(),
+        key_file.to_string(),
+        tenancy.to_string(),
+        region.to_string(),
+    );
+
+    default_profile.write_to_config(path)
+}
 
-/// represents the ADMIN_USER section of the config file.
-#[derive(Debug)]
+/// represents a named admin-user section of the config file (conventionally `ADMIN_USER`).
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Admin {
+    profile_name: String,
     user: String,
     fingerprint: String,
     key_file: String,
@@ -81,43 +149,113 @@ pub struct Admin {
 }
 
 impl Admin {
-    fn new(
-        user: String, 
-        fingerprint: String, 
-        key_file: String, 
+    /// builds an [`Admin`] from its config values, e.g. when re-hydrating one parsed by [`crate::config`].
+    pub fn new(
+        profile_name: String,
+        user: String,
+        fingerprint: String,
+        key_file: String,
         pass_phrase: String
     ) -> Admin {
         Self {
+            profile_name,
             user,
             fingerprint,
             key_file,
             pass_phrase,
         }
     }
-}
 
-/// writes the ADMIN_USER section to the config file.
-pub fn admin(user: &str, fingerprint: &str, key_file: &str, pass_phrase: &str) {
-    // write to config file
-    let config_path = UserDirs::new().unwrap().home_dir().join(".ocloud/config");
-    let config_file = config_path.to_str().expect("Failed to convert path to str");
-    let config = OpenOptions::new()
-        .write(true)
-        .append(true)
-        .open(config_file);
-    match config {
-        Ok(mut config) => {
-            match config.write_all(
-                format!(
-                    "[ADMIN_USER]\nuser={}\nfingerprint={}\nkey_file={}\npass_phrase={}\n\n",
-                    user, fingerprint, key_file, pass_phrase
-                )
-                .as_bytes(),
-            ) {
-                Ok(_) => println!("User data written to file successfully"),
-                Err(e) => println!("Failed to write user data to file: {}", e),
-            }
+    /// the user OCID used in a [`sign::sign`](crate::sign::sign) `keyId`.
+    pub(crate) fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// the key fingerprint used in a [`sign::sign`](crate::sign::sign) `keyId`.
+    pub(crate) fn fingerprint(&self) -> &str {
+        &self.fingerprint
+    }
+
+    /// the path to the PEM private key referenced by this profile.
+    pub(crate) fn key_file(&self) -> &str {
+        &self.key_file
+    }
+
+    /// the passphrase protecting the PEM private key referenced by this profile.
+    pub(crate) fn pass_phrase(&self) -> &str {
+        &self.pass_phrase
+    }
+
+    fn admin_entry(&self) -> String {
+        format!(
+            "[{}]\nuser={}\nfingerprint={}\nkey_file={}\npass_phrase={}\n\n",
+            self.profile_name, self.user, self.fingerprint, self.key_file, self.pass_phrase
+        )
+    }
+
+    fn write_to_config(&self, path: &str) -> Result<(), ConfigError> {
+        // `path` is already fully resolved by `crate::location::resolve`; write it as-is.
+        let config_path = PathBuf::from(path);
+
+        // read first: refuse to duplicate a section that is already there
+        if list_profiles(config_path.to_str().unwrap_or_default())
+            .iter()
+            .any(|name| name == &self.profile_name)
+        {
+            return Err(ConfigError::ConfigExists);
         }
-        Err(e) => println!("Failed to create file: {}", e),
+
+        let mut config = OpenOptions::new()
+            .write(true)
+            .append(true)
+            .open(&config_path)
+            .map_err(|_| ConfigError::PathNotAccessible { path: config_path.clone() })?;
+
+        config
+            .write_all(self.admin_entry().as_bytes())
+            .map_err(|source| ConfigError::WriteContentFailed { file: config_path, source })
     }
-}
\ No newline at end of file
+}
+
+/// writes a named admin-user section to the config file. Fails with [`ConfigError::ConfigExists`] if `profile_name` is already present.
+pub fn admin(path: &str, profile_name: &str, user: &str, fingerprint: &str, key_file: &str, pass_phrase: &str) -> Result<(), ConfigError> {
+    let admin_user = Admin::new(
+        profile_name.to_string(),
+        user.to_string(),
+        fingerprint.to_string(),
+        key_file.to_string(),
+        pass_phrase.to_string(),
+    );
+
+    admin_user.write_to_config(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_round_trips_through_its_display_and_from_str() {
+        let original = Profile::new(
+            "DEV".to_string(),
+            "ocid1.user.oc1..u".to_string(),
+            "ocid1.fingerprint.oc1..f".to_string(),
+            "key_file".to_string(),
+            "ocid1.tenancy.oc1..t".to_string(),
+            "IAD".to_string(),
+        );
+
+        let parsed: Profile = original.to_string().parse().expect("profile_entry text should parse back");
+
+        assert_eq!(parsed.profile_name, "DEV");
+        assert_eq!(parsed.tenancy(), "ocid1.tenancy.oc1..t");
+        assert_eq!(parsed.region, "IAD");
+    }
+
+    #[test]
+    fn from_str_rejects_a_section_missing_a_required_key() {
+        let err = "[DEV]\nuser: ocid1.user.oc1..u\n".parse::<Profile>().unwrap_err();
+
+        assert!(matches!(err, ConfigError::InvalidProfile { .. }));
+    }
+}
@@ -0,0 +1,106 @@
+//! Parses an existing OCI config file back into typed [`Profile`]/[`Admin`] values, backed by `rust-ini`.
+//!
+//! Appending sections is one-directional: without a reader, callers have no way to verify what
+//! ended up on disk or to build edit/update flows on top of it. This module loads the config
+//! lazily with [`Ini::load_from_file`] and exposes lookups over its sections, the way the gcloud
+//! reader loads an `Ini` once and then answers `section(Some("core")).get("account")`.
+//! # Example
+//! ```rust,no_run
+//! use oci_config_writer::config::{get_profile, list_profiles};
+//!
+//! fn main() {
+//!    if let Some(profile) = get_profile(".oci/config", "DEFAULT") {
+//!        println!("{:?}", profile);
+//!    }
+//!    println!("{:?}", list_profiles(".oci/config"));
+//! }
+//! ```
+use ini::Ini;
+
+use crate::account::{Admin, Profile};
+
+/// loads and parses the config file at `path`, or `None` if it doesn't exist or isn't valid INI.
+fn load(path: &str) -> Option<Ini> {
+    Ini::load_from_file(path).ok()
+}
+
+/// looks up a named section (`DEFAULT`, or any named profile such as `DEV`/`PROD`) and returns it as a [`Profile`].
+pub fn get_profile(path: &str, name: &str) -> Option<Profile> {
+    let ini = load(path)?;
+    let section = ini.section(Some(name))?;
+
+    Some(Profile::new(
+        name.to_string(),
+        section.get("user")?.to_string(),
+        section.get("fingerprint")?.to_string(),
+        section.get("key_file")?.to_string(),
+        section.get("tenancy")?.to_string(),
+        section.get("region")?.to_string(),
+    ))
+}
+
+/// looks up the `ADMIN_USER` section and returns it as an [`Admin`].
+pub fn get_admin(path: &str) -> Option<Admin> {
+    let ini = load(path)?;
+    let section = ini.section(Some("ADMIN_USER"))?;
+
+    Some(Admin::new(
+        "ADMIN_USER".to_string(),
+        section.get("user")?.to_string(),
+        section.get("fingerprint")?.to_string(),
+        section.get("key_file")?.to_string(),
+        section.get("pass_phrase")?.to_string(),
+    ))
+}
+
+/// returns the `region` key of a named profile, if the profile and the key both exist.
+pub fn get_region(path: &str, name: &str) -> Option<String> {
+    load(path)?.section(Some(name))?.get("region").map(str::to_string)
+}
+
+/// lists every section name present in the config file (`DEFAULT`, `ADMIN_USER`, and any named profiles).
+pub fn list_profiles(path: &str) -> Vec<String> {
+    match load(path) {
+        Some(ini) => ini.sections().flatten().map(str::to_string).collect(),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::default;
+    use std::fs;
+
+    fn fixture_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("oci_config_writer_test_{}_{}", std::process::id(), name))
+    }
+
+    // `profile_entry` writes `key: value` (colon) while `admin_entry` writes `key=value`
+    // (equals), matching real OCI configs. `rust-ini`'s default parser accepts either
+    // separator, so a single config file mixing both still reads back cleanly.
+    #[test]
+    fn reads_back_a_profile_and_an_admin_user_this_crate_wrote() {
+        let path = fixture_path("reads_back");
+        fs::write(&path, "").unwrap();
+        let path_str = path.to_str().unwrap();
+
+        default(path_str, "DEV", "ocid1.user.oc1..u", "ocid1.fingerprint.oc1..f", "key_file", "ocid1.tenancy.oc1..t", "IAD").unwrap();
+        crate::account::admin(path_str, "ADMIN_USER", "ocid1.user.oc1..u2", "ocid1.fingerprint.oc1..f2", "key_file2", "passphrase").unwrap();
+
+        let profile = get_profile(path_str, "DEV").expect("DEV profile should parse back");
+        assert_eq!(profile.tenancy(), "ocid1.tenancy.oc1..t");
+
+        let admin_user = get_admin(path_str).expect("ADMIN_USER should parse back");
+        assert_eq!(admin_user.user(), "ocid1.user.oc1..u2");
+        assert_eq!(admin_user.fingerprint(), "ocid1.fingerprint.oc1..f2");
+
+        assert_eq!(get_region(path_str, "DEV"), Some("IAD".to_string()));
+
+        let mut profiles = list_profiles(path_str);
+        profiles.sort();
+        assert_eq!(profiles, vec!["ADMIN_USER".to_string(), "DEV".to_string()]);
+
+        fs::remove_file(path).ok();
+    }
+}
@@ -0,0 +1,37 @@
+//! A typed error for the write paths, replacing the `println!`-and-return-`Ok(())` handling that
+//! previously made write failures invisible to callers.
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// errors raised while reading or writing the OCI config file.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// the config path could not be opened, e.g. because the directory is missing or permissions are wrong.
+    #[error("path not accessible: {path}")]
+    PathNotAccessible { path: PathBuf },
+
+    /// writing the section content to the config file failed.
+    #[error("failed to write to {file}")]
+    WriteContentFailed {
+        file: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// the section being written already exists in the config file; it is left untouched.
+    #[error("config already exists")]
+    ConfigExists,
+
+    /// the user's home directory could not be determined.
+    #[error("home directory unavailable")]
+    HomeDirUnavailable,
+
+    /// the config environment variable and the default config location both point at files that exist and disagree.
+    #[error("ambiguous config source: {first} and {second} both exist")]
+    AmbiguousSource { first: PathBuf, second: PathBuf },
+
+    /// text passed to [`std::str::FromStr`] for a [`crate::account::Profile`] wasn't a parseable INI section.
+    #[error("invalid profile: {reason}")]
+    InvalidProfile { reason: String },
+}
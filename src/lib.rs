@@ -1,171 +1,163 @@
-//! This is a small library to manage an Oracle Cloud Infrastructure (OCI) config file. 
+//! This is a small library to manage an Oracle Cloud Infrastructure (OCI) config file.
 //! The library checks, whether a file already exists, before it writes the config into the sub-directory within the user's home directory.
 //! It also checks the permissions before adding content.
-//! 
+//!
 //! More information about the config file itself can be found in the official documentation under: <https://docs.oracle.com/en-us/iaas/Content/API/Concepts/sdkconfig.htm>
 //! # Example
-//! ```rust
+//! ```rust,no_run
 //! use oci_config_writer::{profile, credentials, report};
-//! 
+//!
 //! fn main() {
 //!    profile(
+//!     None,
+//!     "DEV",
 //!     "ocid1.user.oc1..aaaaaaaaxxxxxx",
 //!     "ocid1.fingerprint.oc1..aaaaaaaaxxxxxx",
 //!     "path/to/private/key",
 //!     "ocid1.tenancy.oc1..aaaaaaaaxxxxxx",
 //!     "IAD"
-//!    );
+//!    ).unwrap();
 //!    credentials(
+//!     None,
+//!     "ADMIN_USER",
 //!     "ocid1.user.oc1..aaaaaaaaxxxxxx",
 //!     "ocid1.fingerprint.oc1..aaaaaaaaxxxxxx",
 //!     "path/to/private/key",
 //!     "passphrase"
-//!    );
-//!    report();
+//!    ).unwrap();
+//!    report(None).unwrap();
 //! }
 //! ```
 pub mod file;
 pub mod region;
 pub mod log;
 pub mod account;
+pub mod config;
+pub mod error;
+pub mod location;
+pub mod sign;
 
-use std::fs::OpenOptions;
-use std::io::prelude::*;
-use std::io;
-use std::path::PathBuf;
-use directories::UserDirs;
 use account::{default, admin};
+use error::ConfigError;
 use file::{create, permissions, read};
-use region::{identifier, identifiers};
 
-static DIR: &str = ".oci";
-static NAME: &str = "config";
+pub(crate) static DIR: &str = ".oci";
+pub(crate) static NAME: &str = "config";
 
-// Define the struct representing a file entry
-#[derive(Debug)]
-pub struct Profile {
-    user: &'static str,
-    fingerprint: &'static str,
-    key_file: &'static str,
-    tenancy: &'static str,
-    region: String, // selection of active regions
-}
-
-impl Profile {
-    // Function to format the Profile struct as a string
-    fn profile_entry(&self) -> String {
-        format!("[DEFAULT]\nuser: {}\nfingerprint: {}\nkey_file: {}\ntenancy: {}\nregion: {}\n\n", 
-        self.user, self.fingerprint, self.key_file, self.tenancy, self.region)
-    }
-    
-    // Function to write the struct to the config file
-    fn write_to_config(&self, path: &str) -> io::Result<()> {
-        // define directory directory
-        let config_path = UserDirs::new().unwrap().home_dir().join(path);
-        let path_to_str = config_path.to_str().expect("Failed to convert path to str");
-
-        // set modification properties
-        let config = OpenOptions::new()
-            .write(true)
-            .append(true)
-            .open(path_to_str);
-        match config {
-            Ok(mut config) => {
-                match config.write_all(
-                    self.profile_entry().as_bytes(),
-                ) {
-                    Ok(_) => println!("Tenancy data written to file successfully"),
-                    Err(e) => println!("Failed to write tenancy data to file: {}", e),
-                }
-            }
-            Err(e) => println!("Failed to create file: {}", e),
-        }
-    
-        Ok(())
-    }
-}
-
-/// writes an account profile to the config file, the values are used as defaults for admin users.
+/// writes a named account profile to the config file, the values are used as defaults for admin users.
+///
+/// `path` overrides [`location::resolve`]'s environment/default lookup, e.g. to target a config
+/// file outside the user's home directory.
 /// # Example
-/// ```rust
+/// ```rust,no_run
 /// use oci_config_writer::profile;
-/// 
+///
 /// fn main() {
 ///    profile(
+///     None,
+///     "DEV",
 ///     "ocid1.user.oc1..aaaaaaaaxxxxxx",
 ///     "ocid1.fingerprint.oc1..aaaaaaaaxxxxxx",
 ///     "path/to/private/key",
 ///     "ocid1.tenancy.oc1..aaaaaaaaxxxxxx",
 ///     "IAD"
-///    );
+///    ).unwrap();
 /// }
 /// ```
-pub fn profile(user: &str, fingerprint: &str, key_file: &str, tenancy: &str, home: String) {
-    let default_profile = Profile {
-        user,
-        fingerprint,
-        key_file,
-        tenancy,
-        region: identifier(home)
-    };
-    let mut path = PathBuf::from(DIR);
-    path.push(NAME);
+pub fn profile(path: Option<&str>, profile_name: &str, user: &str, fingerprint: &str, key_file: &str, tenancy: &str, region: &str) -> Result<(), ConfigError> {
+    let path = location::resolve(path)?;
+    let file_path = as_str(&path)?;
 
     if !path.exists() {
-        create(DIR, NAME);
-        // Call the write_to_config method to write the struct to the file
-        if let Err(err) = default_profile.write_to_config(path.to_str().unwrap()) {
-            eprintln!("Error writing to file: {}", err);
-        } else {
-            println!("Profile successfully written to {}", path.to_str().unwrap());
+        if let (Some(dir), Some(name)) = (
+            path.parent().and_then(|p| p.to_str()),
+            path.file_name().and_then(|n| n.to_str()),
+        ) {
+            create(dir, name);
         }
+        secure(&path)?;
     } else {
-        permissions(path.to_str().unwrap());
-        // Call the write_to_config method to write the struct to the file
-        if let Err(err) = default_profile.write_to_config(path.to_str().unwrap()) {
-            eprintln!("Error writing to file: {}", err);
-        } else {
-            println!("Profile successfully written to {}", path.to_str().unwrap());
-        }
+        permissions(file_path);
     }
+
+    default(
+        file_path,
+        profile_name,
+        user,
+        fingerprint,
+        key_file,
+        tenancy,
+        region
+    )
 }
 
-/// adds user credentials to the config file to authenticate the user and to provide access to a defined tenancy.
+/// adds named user credentials to the config file to authenticate the user and to provide access to a defined tenancy.
+///
+/// `path` overrides [`location::resolve`]'s environment/default lookup, e.g. to target a config
+/// file outside the user's home directory.
 /// # Example
-/// ```rust
+/// ```rust,no_run
 /// use oci_config_writer::credentials;
-/// 
+///
 /// fn main() {
 ///    credentials(
+///     None,
+///     "ADMIN_USER",
 ///     "ocid1.user.oc1..aaaaaaaaxxxxxx",
 ///     "ocid1.fingerprint.oc1..aaaaaaaaxxxxxx",
 ///     "path/to/private/key",
 ///     "passphrase"
-///    );
+///    ).unwrap();
 /// }
 /// ```
-pub fn credentials(user: &str, fingerprint: &str, key_file: &str, pass_phrase: &str) {
-    let file_path: String = format!("{}/{}", DIR, NAME); 
+pub fn credentials(path: Option<&str>, profile_name: &str, user: &str, fingerprint: &str, key_file: &str, pass_phrase: &str) -> Result<(), ConfigError> {
+    let path = location::resolve(path)?;
+    let file_path = as_str(&path)?;
 
-    permissions(file_path.as_str());
+    permissions(file_path);
     admin(
-        user, 
-        fingerprint, 
-        key_file, 
+        file_path,
+        profile_name,
+        user,
+        fingerprint,
+        key_file,
         pass_phrase
-    );
+    )
 }
 
 /// reads and returns the content of a config file as a string.
+///
+/// `path` overrides [`location::resolve`]'s environment/default lookup, e.g. to target a config
+/// file outside the user's home directory.
 /// # Example
-/// ```rust
+/// ```rust,no_run
 /// use oci_config_writer::report;
-/// 
+///
 /// fn main() {
-///   report();
+///   report(None).unwrap();
 /// }
 /// ```
-pub fn report() {
-    let file_path: String = format!("{}/{}", DIR, NAME); 
-    read(file_path.as_str());
-}
\ No newline at end of file
+pub fn report(path: Option<&str>) -> Result<(), ConfigError> {
+    let path = location::resolve(path)?;
+    read(as_str(&path)?);
+    Ok(())
+}
+
+/// renders a resolved config path as UTF-8, or a [`ConfigError::PathNotAccessible`] if it isn't one.
+fn as_str(path: &std::path::Path) -> Result<&str, ConfigError> {
+    path.to_str().ok_or_else(|| ConfigError::PathNotAccessible { path: path.to_path_buf() })
+}
+
+/// restricts a freshly created config file to `0600` so the private keys it references aren't world-readable.
+#[cfg(unix)]
+fn secure(path: &std::path::Path) -> Result<(), ConfigError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+        .map_err(|_| ConfigError::PathNotAccessible { path: path.to_path_buf() })
+}
+
+#[cfg(not(unix))]
+fn secure(_path: &std::path::Path) -> Result<(), ConfigError> {
+    Ok(())
+}
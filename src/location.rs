@@ -0,0 +1,52 @@
+//! Resolves where the OCI config file lives, the way the OpenStack module's multi-location
+//! lookup walks an ordered list of candidate paths instead of a single hardcoded one.
+//!
+//! Checks, in order: an explicit path argument, the `OCI_CONFIG_FILE`/`OCI_CLI_CONFIG_FILE`
+//! environment variable, then the `~/.oci/config` default. Returns [`ConfigError::AmbiguousSource`]
+//! (as jj's config loader does) when the environment variable and the default both point at
+//! files that exist and disagree.
+//! # Example
+//! ```rust,no_run
+//! use oci_config_writer::location::resolve;
+//!
+//! fn main() {
+//!    let path = resolve(None).unwrap();
+//!    println!("{}", path.display());
+//! }
+//! ```
+use std::env;
+use std::path::PathBuf;
+
+use directories::UserDirs;
+
+use crate::error::ConfigError;
+use crate::{DIR, NAME};
+
+/// resolves the OCI config file path, honoring `explicit`, then the environment, then the default location.
+pub fn resolve(explicit: Option<&str>) -> Result<PathBuf, ConfigError> {
+    if let Some(path) = explicit {
+        return Ok(PathBuf::from(path));
+    }
+
+    let env_path = env::var_os("OCI_CONFIG_FILE")
+        .or_else(|| env::var_os("OCI_CLI_CONFIG_FILE"))
+        .map(PathBuf::from);
+
+    let Some(env_path) = env_path else {
+        return default_path();
+    };
+
+    // only require a home directory once a default path is actually needed to compare against or
+    // fall back to, so a set `OCI_CONFIG_FILE` is still honored when the home directory isn't.
+    match default_path() {
+        Ok(default_path) if env_path != default_path && env_path.exists() && default_path.exists() => {
+            Err(ConfigError::AmbiguousSource { first: env_path, second: default_path })
+        }
+        _ => Ok(env_path),
+    }
+}
+
+/// the default config path, `~/.oci/config`.
+fn default_path() -> Result<PathBuf, ConfigError> {
+    Ok(UserDirs::new().ok_or(ConfigError::HomeDirUnavailable)?.home_dir().join(DIR).join(NAME))
+}